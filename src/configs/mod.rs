@@ -69,6 +69,7 @@ mod starship_root;
 pub mod status;
 pub mod sudo;
 pub mod swift;
+pub mod terminal;
 pub mod terraform;
 pub mod time;
 pub mod username;
@@ -222,6 +223,8 @@ pub struct FullConfig<'a> {
     #[serde(borrow)]
     swift: swift::SwiftConfig<'a>,
     #[serde(borrow)]
+    terminal: terminal::TerminalConfig<'a>,
+    #[serde(borrow)]
     terraform: terraform::TerraformConfig<'a>,
     #[serde(borrow)]
     time: time::TimeConfig<'a>,
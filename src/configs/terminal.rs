@@ -0,0 +1,25 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct TerminalConfig<'a> {
+    pub format: &'a str,
+    pub symbol: &'a str,
+    pub symbols: IndexMap<String, &'a str>,
+    pub style: &'a str,
+    pub disabled: bool,
+}
+
+impl<'a> Default for TerminalConfig<'a> {
+    fn default() -> Self {
+        TerminalConfig {
+            format: "[$symbol$name( $version)]($style) ",
+            symbol: "🖥️  ",
+            symbols: IndexMap::new(),
+            style: "bold white",
+            disabled: true,
+        }
+    }
+}
@@ -76,6 +76,7 @@ pub const ALL_MODULES: &[&str] = &[
     "status",
     "sudo",
     "swift",
+    "terminal",
     "terraform",
     "time",
     "username",
@@ -15,35 +15,48 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     pub fn container_name(context: &Context) -> Option<String> {
         use crate::utils::context_path;
 
+        // Set by distrobox and toolbox, which run on top of podman or docker
+        let container_id = context.get_env("CONTAINER_ID").filter(|id| !id.is_empty());
+
         if context_path(context, "/proc/vz").exists() && !context_path(context, "/proc/bc").exists()
         {
             // OpenVZ
-            return Some("OpenVZ".into());
+            return Some(container_id.unwrap_or_else(|| "OpenVZ".into()));
         }
 
         if context_path(context, "/run/host/container-manager").exists() {
             // OCI
-            return Some("OCI".into());
+            return Some(container_id.unwrap_or_else(|| "OCI".into()));
         }
 
         if context_path(context, "/run/systemd/container").exists() {
             // systemd
-            return Some("Systemd".into());
+            return Some(container_id.unwrap_or_else(|| "Systemd".into()));
         }
 
         let container_env_path = context_path(context, "/run/.containerenv");
 
         if container_env_path.exists() {
             // podman and others
+            if container_id.is_some() {
+                return container_id;
+            }
 
             let image_res = read_file(container_env_path)
                 .map(|s| {
                     s.lines()
                         .find_map(|l| {
-                            l.starts_with("image=\"").then(|| {
-                                let r = l.split_at(7).1;
-                                let name = r.rfind('/').map(|n| r.split_at(n + 1).1);
-                                String::from(name.unwrap_or(r).trim_end_matches('"'))
+                            l.strip_prefix("name=\"")
+                                .map(|name| String::from(name.trim_end_matches('"')))
+                                .filter(|name| !name.is_empty())
+                        })
+                        .or_else(|| {
+                            s.lines().find_map(|l| {
+                                l.starts_with("image=\"").then(|| {
+                                    let r = l.split_at(7).1;
+                                    let name = r.rfind('/').map(|n| r.split_at(n + 1).1);
+                                    String::from(name.unwrap_or(r).trim_end_matches('"'))
+                                })
                             })
                         })
                         .unwrap_or_else(|| "podman".into())
@@ -55,7 +68,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
         if context_path(context, "/.dockerenv").exists() {
             // docker
-            return Some("Docker".into());
+            return Some(container_id.unwrap_or_else(|| "Docker".into()));
+        }
+
+        if let Some(container) = context.get_env("container").filter(|c| !c.is_empty()) {
+            // systemd-nspawn, LXC and others
+            return Some(container_id.unwrap_or(container));
         }
 
         None
@@ -119,16 +137,24 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
-    fn containerenv(name: Option<&str>) -> std::io::Result<(Option<String>, Option<String>)> {
+    fn containerenv(
+        image: Option<&str>,
+        name: Option<&str>,
+        container_id: Option<&str>,
+    ) -> std::io::Result<(Option<String>, Option<String>)> {
         use std::io::Write;
 
-        let renderer = ModuleRenderer::new("container")
+        let mut renderer = ModuleRenderer::new("container")
             // For a custom config
             .config(toml::toml! {
                [container]
                disabled = false
             });
 
+        if let Some(container_id) = container_id {
+            renderer = renderer.env("CONTAINER_ID", container_id);
+        }
+
         let root_path = renderer.root_path();
 
         let mut containerenv = PathBuf::from(root_path);
@@ -141,7 +167,10 @@ mod tests {
         containerenv.push(".containerenv");
         let mut file = std::fs::File::create(&containerenv)?;
         if let Some(name) = name {
-            file.write_all(format!("image=\"{}\"\n", name).as_bytes())?;
+            file.write_all(format!("name=\"{}\"\n", name).as_bytes())?;
+        }
+        if let Some(image) = image {
+            file.write_all(format!("image=\"{}\"\n", image).as_bytes())?;
         }
 
         // The output of the module
@@ -152,10 +181,10 @@ mod tests {
         // The value that should be rendered by the module.
         let expected = Some(format!(
             "{} ",
-            Color::Red
-                .bold()
-                .dimmed()
-                .paint(format!("⬢ [{}]", name.unwrap_or("podman")))
+            Color::Red.bold().dimmed().paint(format!(
+                "⬢ [{}]",
+                container_id.or(name).or(image).unwrap_or("podman")
+            ))
         ));
 
         Ok((actual, expected))
@@ -164,7 +193,7 @@ mod tests {
     #[test]
     #[cfg(target_os = "linux")]
     fn test_containerenv() -> std::io::Result<()> {
-        let (actual, expected) = containerenv(None)?;
+        let (actual, expected) = containerenv(None, None, None)?;
 
         // Assert that the actual and expected values are the same
         assert_eq!(actual, expected);
@@ -175,7 +204,19 @@ mod tests {
     #[test]
     #[cfg(target_os = "linux")]
     fn test_containerenv_fedora() -> std::io::Result<()> {
-        let (actual, expected) = containerenv(Some("fedora-toolbox:35"))?;
+        let (actual, expected) = containerenv(Some("fedora-toolbox:35"), None, None)?;
+
+        // Assert that the actual and expected values are the same
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_containerenv_name() -> std::io::Result<()> {
+        let (actual, expected) =
+            containerenv(Some("fedora-toolbox:35"), Some("fedora-toolbox-35"), None)?;
 
         // Assert that the actual and expected values are the same
         assert_eq!(actual, expected);
@@ -183,10 +224,136 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_containerenv_container_id() -> std::io::Result<()> {
+        let (actual, expected) = containerenv(
+            Some("fedora-toolbox:35"),
+            Some("fedora-toolbox-35"),
+            Some("my-distrobox"),
+        )?;
+
+        // Assert that the actual and expected values are the same
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_dockerenv_container_id() -> std::io::Result<()> {
+        let renderer = ModuleRenderer::new("container").env("CONTAINER_ID", "my-distrobox");
+
+        std::fs::File::create(renderer.root_path().join(".dockerenv"))?;
+
+        let actual = renderer.collect();
+        let expected = Some(format!(
+            "{} ",
+            Color::Red.bold().dimmed().paint("⬢ [my-distrobox]")
+        ));
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_openvz_container_id() -> std::io::Result<()> {
+        let renderer = ModuleRenderer::new("container").env("CONTAINER_ID", "my-container");
+
+        std::fs::DirBuilder::new()
+            .recursive(true)
+            .create(renderer.root_path().join("proc/vz"))?;
+
+        let actual = renderer.collect();
+        let expected = Some(format!(
+            "{} ",
+            Color::Red.bold().dimmed().paint("⬢ [my-container]")
+        ));
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_oci_container_id() -> std::io::Result<()> {
+        let renderer = ModuleRenderer::new("container").env("CONTAINER_ID", "my-toolbox");
+
+        let mut container_manager = renderer.root_path().join("run/host");
+        std::fs::DirBuilder::new()
+            .recursive(true)
+            .create(&container_manager)?;
+        container_manager.push("container-manager");
+        std::fs::File::create(&container_manager)?;
+
+        let actual = renderer.collect();
+        let expected = Some(format!(
+            "{} ",
+            Color::Red.bold().dimmed().paint("⬢ [my-toolbox]")
+        ));
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_container_env_var() {
+        let actual = ModuleRenderer::new("container")
+            .env("container", "lxc")
+            .collect();
+        let expected = Some(format!("{} ", Color::Red.bold().dimmed().paint("⬢ [lxc]")));
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_container_env_var_with_container_id() {
+        let actual = ModuleRenderer::new("container")
+            .env("container", "oci")
+            .env("CONTAINER_ID", "dev-box")
+            .collect();
+        let expected = Some(format!(
+            "{} ",
+            Color::Red.bold().dimmed().paint("⬢ [dev-box]")
+        ));
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_container_env_var_empty() {
+        let actual = ModuleRenderer::new("container")
+            .env("container", "")
+            .env("CONTAINER_ID", "")
+            .collect();
+        let expected = None;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_container_env_var_empty_container_id() {
+        let actual = ModuleRenderer::new("container")
+            .env("container", "lxc")
+            .env("CONTAINER_ID", "")
+            .collect();
+        let expected = Some(format!("{} ", Color::Red.bold().dimmed().paint("⬢ [lxc]")));
+
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     #[cfg(not(target_os = "linux"))]
     fn test_containerenv() -> std::io::Result<()> {
-        let (actual, expected) = containerenv(None)?;
+        let (actual, expected) = containerenv(None, None, None)?;
 
         // Assert that the actual and expected values are not the same
         assert_ne!(actual, expected);
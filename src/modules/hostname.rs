@@ -4,18 +4,19 @@ use std::ffi::OsString;
 use crate::config::ModuleConfig;
 use crate::configs::hostname::HostnameConfig;
 use crate::formatter::StringFormatter;
+use crate::utils::is_ssh_session;
 
 /// Creates a module with the system hostname
 ///
 /// Will display the hostname if all of the following criteria are met:
 ///     - hostname.disabled is absent or false
-///     - `hostname.ssh_only` is false OR the user is currently connected as an SSH session (`$SSH_CONNECTION`)
+///     - `hostname.ssh_only` is false OR the user is currently connected as an SSH session (`$SSH_CONNECTION`, `$SSH_CLIENT` or `$SSH_TTY`)
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("hostname");
     let config: HostnameConfig = HostnameConfig::try_load(module.config);
 
-    let ssh_connection = context.get_env("SSH_CONNECTION");
-    if config.ssh_only && ssh_connection.is_none() {
+    let is_ssh = is_ssh_session(context);
+    if config.ssh_only && !is_ssh {
         return None;
     }
 
@@ -45,7 +46,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         formatter
             .map_meta(|var, _| match var {
                 "ssh_symbol" => {
-                    if ssh_connection.is_some() {
+                    if is_ssh {
                         Some(config.ssh_symbol)
                     } else {
                         None
@@ -156,6 +157,25 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn ssh_tty() {
+        let hostname = get_hostname!();
+        let actual = ModuleRenderer::new("hostname")
+            .config(toml::toml! {
+                [hostname]
+                ssh_only = true
+                trim_at = ""
+            })
+            .env("SSH_TTY", "/dev/pts/0")
+            .collect();
+        let expected = Some(format!(
+            "{} in ",
+            style().paint("🌐 ".to_owned() + &hostname)
+        ));
+
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn no_trim_at() {
         let hostname = get_hostname!();
@@ -3,6 +3,7 @@ use super::{Context, Module};
 use crate::config::ModuleConfig;
 use crate::configs::localip::LocalipConfig;
 use crate::formatter::StringFormatter;
+use crate::utils::is_ssh_session;
 
 /// Creates a module with the ipv4 address of the local machine.
 ///
@@ -12,7 +13,7 @@ use crate::formatter::StringFormatter;
 ///
 /// Will display the ip if all of the following criteria are met:
 ///     - localip.disabled is false
-///     - `localip.ssh_only` is false OR the user is currently connected as an SSH session (`$SSH_CONNECTION`)
+///     - `localip.ssh_only` is false OR the user is currently connected as an SSH session (`$SSH_CONNECTION`, `$SSH_CLIENT` or `$SSH_TTY`)
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("localip");
     let config: LocalipConfig = LocalipConfig::try_load(module.config);
@@ -23,8 +24,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     };
 
-    let ssh_connection = context.get_env("SSH_CONNECTION");
-    if config.ssh_only && ssh_connection.is_none() {
+    if config.ssh_only && !is_ssh_session(context) {
         return None;
     }
 
@@ -130,6 +130,38 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn ssh_client() {
+        let localip = get_localip!();
+        let actual = ModuleRenderer::new("localip")
+            .config(toml::toml! {
+                [localip]
+                ssh_only = true
+                disabled = false
+            })
+            .env("SSH_CLIENT", "192.168.0.101 39323 22")
+            .collect();
+        let expected = Some(format!("{} ", style().paint(localip)));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn ssh_tty() {
+        let localip = get_localip!();
+        let actual = ModuleRenderer::new("localip")
+            .config(toml::toml! {
+                [localip]
+                ssh_only = true
+                disabled = false
+            })
+            .env("SSH_TTY", "/dev/pts/0")
+            .collect();
+        let expected = Some(format!("{} ", style().paint(localip)));
+
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn config_blank() {
         let actual = ModuleRenderer::new("localip")
@@ -65,6 +65,7 @@ mod spack;
 mod status;
 mod sudo;
 mod swift;
+mod terminal;
 mod terraform;
 mod time;
 mod username;
@@ -157,6 +158,7 @@ pub fn handle<'a>(module: &str, context: &'a Context) -> Option<Module<'a>> {
             "swift" => swift::module(context),
             "status" => status::module(context),
             "sudo" => sudo::module(context),
+            "terminal" => terminal::module(context),
             "terraform" => terraform::module(context),
             "time" => time::module(context),
             "crystal" => crystal::module(context),
@@ -260,6 +262,7 @@ pub fn description(module: &str) -> &'static str {
         "status" => "The status of the last command",
         "sudo" => "The sudo credentials are currently cached",
         "swift" => "The currently installed version of Swift",
+        "terminal" => "The current terminal emulator",
         "terraform" => "The currently selected terraform workspace and version",
         "time" => "The current local time",
         "username" => "The active user's username",
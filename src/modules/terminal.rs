@@ -0,0 +1,298 @@
+use super::{Context, Module, ModuleConfig};
+
+use crate::configs::terminal::TerminalConfig;
+use crate::formatter::StringFormatter;
+
+/// Creates a module with the current terminal emulator
+///
+/// Will display the terminal if all of the following criteria are met:
+///     - terminal.disabled is false
+///     - The terminal can be identified from the environment
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("terminal");
+    let config: TerminalConfig = TerminalConfig::try_load(module.config);
+
+    if config.disabled {
+        return None;
+    }
+
+    let (name, version) = get_terminal(context)?;
+
+    let parsed = StringFormatter::new(config.format).and_then(|formatter| {
+        formatter
+            .map_meta(|variable, _| match variable {
+                "symbol" => Some(
+                    config
+                        .symbols
+                        .iter()
+                        .find(|(key, _)| key.eq_ignore_ascii_case(&name))
+                        .map_or(config.symbol, |(_, symbol)| *symbol),
+                ),
+                _ => None,
+            })
+            .map_style(|variable| match variable {
+                "style" => Some(Ok(config.style)),
+                _ => None,
+            })
+            .map(|variable| match variable {
+                "name" => Some(Ok(name.as_str())),
+                "version" => version.as_deref().map(Ok),
+                _ => None,
+            })
+            .parse(None, Some(context))
+    });
+
+    module.set_segments(match parsed {
+        Ok(segments) => segments,
+        Err(error) => {
+            log::warn!("Error in module `terminal`:\n{}", error);
+            return None;
+        }
+    });
+
+    Some(module)
+}
+
+/// Identifies the terminal emulator, returning its name and version (if known)
+///
+/// `$TERM_PROGRAM` is preferred since most emulators that set it also set
+/// `$TERM_PROGRAM_VERSION`, then emulator-specific variables, then `$TERM`.
+/// Multiplexers also set `$TERM_PROGRAM`, so they are skipped to report the
+/// emulator they run in instead.
+fn get_terminal(context: &Context) -> Option<(String, Option<String>)> {
+    let get_env = |key| context.get_env(key).filter(|value| !value.is_empty());
+
+    if let Some(program) = get_env("TERM_PROGRAM") {
+        let name = match program.as_str() {
+            "tmux" | "screen" => None,
+            "iTerm.app" => Some("iterm2"),
+            "Apple_Terminal" => Some("apple_terminal"),
+            "WezTerm" => Some("wezterm"),
+            "Hyper" => Some("hyper"),
+            program => Some(program),
+        };
+        if let Some(name) = name {
+            return Some((name.to_lowercase(), get_env("TERM_PROGRAM_VERSION")));
+        }
+    }
+
+    if let Some(version) = get_env("KONSOLE_VERSION") {
+        return Some(("konsole".to_string(), Some(version)));
+    }
+
+    const EMULATOR_VARS: &[(&str, &str)] = &[
+        ("KITTY_WINDOW_ID", "kitty"),
+        ("ALACRITTY_SOCKET", "alacritty"),
+        ("ALACRITTY_LOG", "alacritty"),
+        ("WEZTERM_EXECUTABLE", "wezterm"),
+        ("WT_SESSION", "windows_terminal"),
+        ("GNOME_TERMINAL_SCREEN", "gnome_terminal"),
+        ("TERMINATOR_UUID", "terminator"),
+        ("TILIX_ID", "tilix"),
+    ];
+
+    if let Some((_, name)) = EMULATOR_VARS.iter().find(|(var, _)| get_env(var).is_some()) {
+        return Some((name.to_string(), None));
+    }
+
+    let name = match context.get_env("TERM")?.as_str() {
+        "xterm-kitty" => "kitty",
+        "alacritty" => "alacritty",
+        "wezterm" => "wezterm",
+        "foot" | "foot-extra" => "foot",
+        _ => return None,
+    };
+
+    Some((name.to_string(), None))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_none_if_disabled() {
+        let actual = ModuleRenderer::new("terminal")
+            .env("TERM_PROGRAM", "WezTerm")
+            .collect();
+        let expected = None;
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_none_if_unknown() {
+        let actual = ModuleRenderer::new("terminal")
+            .env("TERM", "xterm-256color")
+            .config(toml::toml! {
+                [terminal]
+                disabled = false
+            })
+            .collect();
+        let expected = None;
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_term_program_with_version() {
+        let actual = ModuleRenderer::new("terminal")
+            .env("TERM_PROGRAM", "iTerm.app")
+            .env("TERM_PROGRAM_VERSION", "3.4.16")
+            .config(toml::toml! {
+                [terminal]
+                disabled = false
+            })
+            .collect();
+        let expected = Some(format!(
+            "{} ",
+            Color::White.bold().paint("🖥️  iterm2 3.4.16")
+        ));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_term_program_unmapped() {
+        let actual = ModuleRenderer::new("terminal")
+            .env("TERM_PROGRAM", "vscode")
+            .config(toml::toml! {
+                [terminal]
+                disabled = false
+            })
+            .collect();
+        let expected = Some(format!("{} ", Color::White.bold().paint("🖥️  vscode")));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_emulator_var() {
+        let actual = ModuleRenderer::new("terminal")
+            .env("KITTY_WINDOW_ID", "1")
+            .config(toml::toml! {
+                [terminal]
+                disabled = false
+            })
+            .collect();
+        let expected = Some(format!("{} ", Color::White.bold().paint("🖥️  kitty")));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_konsole_version() {
+        let actual = ModuleRenderer::new("terminal")
+            .env("KONSOLE_VERSION", "220401")
+            .config(toml::toml! {
+                [terminal]
+                disabled = false
+            })
+            .collect();
+        let expected = Some(format!(
+            "{} ",
+            Color::White.bold().paint("🖥️  konsole 220401")
+        ));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_term_fallback() {
+        let actual = ModuleRenderer::new("terminal")
+            .env("TERM", "alacritty")
+            .config(toml::toml! {
+                [terminal]
+                disabled = false
+            })
+            .collect();
+        let expected = Some(format!("{} ", Color::White.bold().paint("🖥️  alacritty")));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_term_program_takes_precedence() {
+        let actual = ModuleRenderer::new("terminal")
+            .env("TERM_PROGRAM", "WezTerm")
+            .env("TERM_PROGRAM_VERSION", "20220624")
+            .env("KITTY_WINDOW_ID", "1")
+            .config(toml::toml! {
+                [terminal]
+                disabled = false
+            })
+            .collect();
+        let expected = Some(format!(
+            "{} ",
+            Color::White.bold().paint("🖥️  wezterm 20220624")
+        ));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_multiplexer_skipped() {
+        let actual = ModuleRenderer::new("terminal")
+            .env("TERM_PROGRAM", "tmux")
+            .env("TERM_PROGRAM_VERSION", "3.3a")
+            .env("KITTY_WINDOW_ID", "1")
+            .config(toml::toml! {
+                [terminal]
+                disabled = false
+            })
+            .collect();
+        let expected = Some(format!("{} ", Color::White.bold().paint("🖥️  kitty")));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_multiplexer_only() {
+        let actual = ModuleRenderer::new("terminal")
+            .env("TERM_PROGRAM", "tmux")
+            .env("TERM", "tmux-256color")
+            .config(toml::toml! {
+                [terminal]
+                disabled = false
+            })
+            .collect();
+        let expected = None;
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_empty_vars_skipped() {
+        let actual = ModuleRenderer::new("terminal")
+            .env("TERM_PROGRAM", "")
+            .env("KONSOLE_VERSION", "")
+            .env("KITTY_WINDOW_ID", "")
+            .env("TERM", "alacritty")
+            .config(toml::toml! {
+                [terminal]
+                disabled = false
+            })
+            .collect();
+        let expected = Some(format!("{} ", Color::White.bold().paint("🖥️  alacritty")));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_symbols() {
+        let actual = ModuleRenderer::new("terminal")
+            .env("TERM_PROGRAM", "WezTerm")
+            .config(toml::toml! {
+                [terminal]
+                disabled = false
+                format = "[$symbol$name]($style)"
+                [terminal.symbols]
+                WezTerm = "W "
+            })
+            .collect();
+        let expected = Some(format!("{}", Color::White.bold().paint("W wezterm")));
+
+        assert_eq!(expected, actual);
+    }
+}
@@ -2,6 +2,7 @@ use super::{Context, Module, ModuleConfig};
 
 use crate::configs::username::UsernameConfig;
 use crate::formatter::StringFormatter;
+use crate::utils::is_ssh_session;
 
 #[cfg(not(target_os = "windows"))]
 const USERNAME_ENV_VAR: &str = "USER";
@@ -14,7 +15,7 @@ const USERNAME_ENV_VAR: &str = "USERNAME";
 /// Will display the username if any of the following criteria are met:
 ///     - The current user is root (UID = 0) [1]
 ///     - The current user isn't the same as the one that is logged in (`$LOGNAME` != `$USER`) [2]
-///     - The user is currently connected as an SSH session (`$SSH_CONNECTION`) [3]
+///     - The user is currently connected as an SSH session (`$SSH_CONNECTION`, `$SSH_CLIENT` or `$SSH_TTY`) [3]
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut username = context.get_env(USERNAME_ENV_VAR)?;
 
@@ -102,11 +103,6 @@ fn is_root_user() -> bool {
     nix::unistd::geteuid() == nix::unistd::ROOT
 }
 
-fn is_ssh_session(context: &Context) -> bool {
-    let ssh_env = ["SSH_CONNECTION", "SSH_CLIENT", "SSH_TTY"];
-    ssh_env.iter().any(|env| context.get_env_os(env).is_some())
-}
-
 #[cfg(test)]
 mod tests {
     use crate::test::ModuleRenderer;
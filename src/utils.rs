@@ -33,6 +33,12 @@ pub fn context_path<S: AsRef<OsStr> + ?Sized>(context: &Context, s: &S) -> PathB
     }
 }
 
+/// Checks if the user is connected through an SSH session
+pub fn is_ssh_session(context: &Context) -> bool {
+    let ssh_env = ["SSH_CONNECTION", "SSH_CLIENT", "SSH_TTY"];
+    ssh_env.iter().any(|env| context.get_env_os(env).is_some())
+}
+
 /// Return the string contents of a file
 pub fn read_file<P: AsRef<Path> + Debug>(file_name: P) -> Result<String> {
     log::trace!("Trying to read from {:?}", file_name);
@@ -561,6 +567,7 @@ pub fn encode_to_hex(slice: &[u8]) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test::default_context;
 
     #[test]
     fn test_0ms() {
@@ -739,4 +746,18 @@ mod tests {
             "080d09bd815e".to_string()
         );
     }
+
+    #[test]
+    fn test_is_ssh_session() {
+        for ssh_env in ["SSH_CONNECTION", "SSH_CLIENT", "SSH_TTY"] {
+            let mut context = default_context();
+            context.env.insert(ssh_env, "something".to_string());
+            assert!(is_ssh_session(&context), "{} should be detected", ssh_env);
+        }
+    }
+
+    #[test]
+    fn test_is_not_ssh_session() {
+        assert!(!is_ssh_session(&default_context()));
+    }
 }